@@ -0,0 +1,156 @@
+use alvr_packets::ServerRequest;
+use eframe::egui::{self, RichText};
+use std::path::PathBuf;
+
+pub enum InstallationTabRequest {
+    OpenSetupWizard,
+    ServerRequest(ServerRequest),
+    #[cfg(windows)]
+    AddFirewallRules,
+    #[cfg(windows)]
+    RemoveFirewallRules,
+    #[cfg(target_os = "linux")]
+    AddSteamShortcut,
+    #[cfg(target_os = "linux")]
+    RemoveSteamShortcut,
+    ExportDiagnostics,
+}
+
+pub struct InstallationTab {
+    drivers: Vec<PathBuf>,
+    #[cfg(windows)]
+    firewall_status: Option<Result<(), String>>,
+    #[cfg(target_os = "linux")]
+    steam_shortcut_status: Option<Result<(), String>>,
+    diagnostics_status: Option<Result<PathBuf, String>>,
+}
+
+impl InstallationTab {
+    pub fn new() -> Self {
+        Self {
+            drivers: vec![],
+            #[cfg(windows)]
+            firewall_status: None,
+            #[cfg(target_os = "linux")]
+            steam_shortcut_status: None,
+            diagnostics_status: None,
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn notify_firewall_result(&mut self, result: Result<(), String>) {
+        self.firewall_status = Some(result);
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn notify_steam_shortcut_result(&mut self, result: Result<(), String>) {
+        self.steam_shortcut_status = Some(result);
+    }
+
+    pub fn notify_diagnostics_exported(&mut self, result: Result<PathBuf, String>) {
+        self.diagnostics_status = Some(result);
+    }
+
+    pub fn update_drivers(&mut self, list: Vec<PathBuf>) {
+        self.drivers = list;
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> Vec<InstallationTabRequest> {
+        let mut requests = vec![];
+
+        if ui.button("Run setup wizard").clicked() {
+            requests.push(InstallationTabRequest::OpenSetupWizard);
+        }
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Registered drivers").strong());
+        for driver in &self.drivers {
+            ui.label(driver.display().to_string());
+        }
+
+        #[cfg(windows)]
+        {
+            ui.add_space(10.0);
+            ui.label(RichText::new("Windows Firewall").strong());
+            ui.label(
+                "Allow the ALVR server through Windows Firewall so clients on the local \
+                 network can find it.",
+            );
+
+            ui.horizontal(|ui| {
+                if ui.button("Allow ALVR through the firewall").clicked() {
+                    requests.push(InstallationTabRequest::AddFirewallRules);
+                }
+
+                if ui.button("Remove firewall rules").clicked() {
+                    requests.push(InstallationTabRequest::RemoveFirewallRules);
+                }
+            });
+
+            match &self.firewall_status {
+                Some(Ok(())) => {
+                    ui.colored_label(alvr_gui_common::theme::OK_GREEN, "Firewall rules updated");
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(alvr_gui_common::theme::KO_RED, format!("Failed: {e}"));
+                }
+                None => (),
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            ui.add_space(10.0);
+            ui.label(RichText::new("Steam").strong());
+            ui.label(
+                "Add ALVR as a non-Steam game so it shows up in Gaming Mode on the Steam Deck.",
+            );
+
+            ui.horizontal(|ui| {
+                if ui.button("Add to Steam").clicked() {
+                    requests.push(InstallationTabRequest::AddSteamShortcut);
+                }
+
+                if ui.button("Remove from Steam").clicked() {
+                    requests.push(InstallationTabRequest::RemoveSteamShortcut);
+                }
+            });
+
+            match &self.steam_shortcut_status {
+                Some(Ok(())) => {
+                    ui.colored_label(alvr_gui_common::theme::OK_GREEN, "Steam shortcut up to date");
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(alvr_gui_common::theme::KO_RED, format!("Failed: {e}"));
+                }
+                None => (),
+            }
+        }
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Diagnostics").strong());
+        ui.label(
+            "Bundle recent logs, the current session config and the SteamVR connection state \
+             into a zip you can attach to a bug report. Install state is not included.",
+        );
+
+        if ui.button("Export diagnostics").clicked() {
+            requests.push(InstallationTabRequest::ExportDiagnostics);
+        }
+
+        match &self.diagnostics_status {
+            Some(Ok(path)) => {
+                ui.colored_label(
+                    alvr_gui_common::theme::OK_GREEN,
+                    format!("Saved to {}", path.display()),
+                );
+            }
+            Some(Err(e)) => {
+                ui.colored_label(alvr_gui_common::theme::KO_RED, format!("Failed: {e}"));
+            }
+            None => (),
+        }
+
+        requests
+    }
+}
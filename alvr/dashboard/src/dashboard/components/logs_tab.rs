@@ -0,0 +1,127 @@
+// Keeps a scrollback of every log event the server sends and lets the user narrow it down by
+// minimum severity and a free-text match against the message (which is where span/target
+// context ends up once the server's formatter has run), since wading through everything at
+// once is unworkable once the server has been running for a while.
+use alvr_events::{Event, EventType, Severity};
+use eframe::egui::{self, RichText, ScrollArea, TextEdit, WidgetInfo, WidgetType};
+
+const SCROLLBACK_SIZE: usize = 2000;
+
+struct LogLine {
+    severity: Severity,
+    message: String,
+}
+
+fn severity_rank(severity: &Severity) -> u8 {
+    match severity {
+        Severity::Error => 3,
+        Severity::Warning => 2,
+        Severity::Info => 1,
+        Severity::Debug => 0,
+    }
+}
+
+fn severity_label(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "Error",
+        Severity::Warning => "Warning",
+        Severity::Info => "Info",
+        Severity::Debug => "Debug",
+    }
+}
+
+pub struct LogsTab {
+    lines: std::collections::VecDeque<LogLine>,
+    min_severity: Severity,
+    filter_text: String,
+}
+
+impl LogsTab {
+    pub fn new() -> Self {
+        Self {
+            lines: std::collections::VecDeque::with_capacity(SCROLLBACK_SIZE),
+            min_severity: Severity::Debug,
+            filter_text: String::new(),
+        }
+    }
+
+    pub fn push_event(&mut self, event: Event) {
+        if let EventType::Log(log_event) = event.event_type {
+            if self.lines.len() == SCROLLBACK_SIZE {
+                self.lines.pop_front();
+            }
+
+            self.lines.push_back(LogLine {
+                severity: log_event.severity,
+                message: log_event.message,
+            });
+        }
+    }
+
+    // No settings currently affect log filtering, but this mirrors the `update_settings` hook
+    // every other tab has, so wiring up one in the future (e.g. a configurable scrollback size)
+    // doesn't require touching `dashboard/mod.rs` again.
+    pub fn update_settings(&mut self, _settings: &alvr_session::Settings) {}
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Minimum level:");
+
+            for severity in [
+                Severity::Debug,
+                Severity::Info,
+                Severity::Warning,
+                Severity::Error,
+            ] {
+                let label = severity_label(&severity);
+                let selected = self.min_severity == severity;
+                let response = ui.selectable_value(&mut self.min_severity, severity, label);
+                response.widget_info(|| {
+                    WidgetInfo::selected(WidgetType::SelectableLabel, true, selected, label)
+                });
+            }
+
+            ui.add_space(10.0);
+            ui.label("Filter:");
+            let response = ui.add(
+                TextEdit::singleline(&mut self.filter_text).hint_text("span or message text"),
+            );
+            response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::TextEdit, true, self.filter_text.clone())
+            });
+        });
+
+        ui.add_space(5.0);
+
+        ScrollArea::vertical().stick_to_bottom(true).show(ui, |ui| {
+            for line in self
+                .lines
+                .iter()
+                .filter(|line| severity_rank(&line.severity) >= severity_rank(&self.min_severity))
+                .filter(|line| {
+                    self.filter_text.is_empty()
+                        || line
+                            .message
+                            .to_lowercase()
+                            .contains(&self.filter_text.to_lowercase())
+                })
+            {
+                let color = match line.severity {
+                    Severity::Error => alvr_gui_common::theme::KO_RED,
+                    Severity::Warning => egui::Color32::YELLOW,
+                    Severity::Info | Severity::Debug => ui.visuals().text_color(),
+                };
+
+                let response =
+                    ui.label(RichText::new(&line.message).color(color).monospace());
+                response.widget_info(|| {
+                    WidgetInfo::labeled(
+                        WidgetType::Label,
+                        true,
+                        format!("{}: {}", severity_label(&line.severity), line.message),
+                    )
+                });
+            }
+        });
+    }
+}
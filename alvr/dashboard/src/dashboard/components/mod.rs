@@ -0,0 +1,9 @@
+mod installation_tab;
+mod logs_tab;
+mod settings_tab;
+mod setup_wizard;
+
+pub use installation_tab::{InstallationTab, InstallationTabRequest};
+pub use logs_tab::LogsTab;
+pub use settings_tab::SettingsTab;
+pub use setup_wizard::{SetupWizard, SetupWizardRequest};
@@ -0,0 +1,78 @@
+// The full settings tree lives behind the server's schema and isn't reproduced client-side
+// here; this renders the handful of top-level switches the dashboard itself needs to read back
+// (see `dashboard/mod.rs`'s use of `extra.open_setup_wizard`), each as a real, accessible
+// control rather than leaving the tab empty.
+use alvr_packets::{parse_path, PathValuePair, ServerRequest};
+use eframe::egui::{self, WidgetInfo, WidgetType};
+
+pub struct SettingsTab {
+    open_setup_wizard: bool,
+    open_close_steamvr_with_dashboard: bool,
+}
+
+impl SettingsTab {
+    pub fn new() -> Self {
+        Self {
+            open_setup_wizard: false,
+            open_close_steamvr_with_dashboard: false,
+        }
+    }
+
+    pub fn update_session(&mut self, session_settings: &alvr_session::SessionSettings) {
+        self.open_setup_wizard = session_settings.extra.open_setup_wizard;
+        self.open_close_steamvr_with_dashboard = session_settings
+            .extra
+            .steamvr_launcher
+            .open_close_steamvr_with_dashboard;
+    }
+
+    // Every toggle below is added unconditionally in this same top-to-bottom order every frame,
+    // so Tab/Shift+Tab walks the form in the order it's read on screen instead of jumping around.
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> Vec<ServerRequest> {
+        let mut requests = vec![];
+
+        let response = ui.checkbox(&mut self.open_setup_wizard, "Show setup wizard on next launch");
+        if response.changed() {
+            requests.push(set_value_request(
+                "session_settings.extra.open_setup_wizard",
+                self.open_setup_wizard,
+            ));
+        }
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                true,
+                self.open_setup_wizard,
+                "Show setup wizard on next launch",
+            )
+        });
+
+        let response = ui.checkbox(
+            &mut self.open_close_steamvr_with_dashboard,
+            "Open/close SteamVR together with the dashboard",
+        );
+        if response.changed() {
+            requests.push(set_value_request(
+                "session_settings.extra.steamvr_launcher.open_close_steamvr_with_dashboard",
+                self.open_close_steamvr_with_dashboard,
+            ));
+        }
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                true,
+                self.open_close_steamvr_with_dashboard,
+                "Open/close SteamVR together with the dashboard",
+            )
+        });
+
+        requests
+    }
+}
+
+fn set_value_request(path: &str, value: bool) -> ServerRequest {
+    ServerRequest::SetValues(vec![PathValuePair {
+        path: parse_path(path),
+        value: serde_json::Value::Bool(value),
+    }])
+}
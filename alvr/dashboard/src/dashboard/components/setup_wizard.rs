@@ -0,0 +1,95 @@
+// A short first-run walkthrough shown until the user finishes (or skips) it once. Each step is
+// plain text plus Back/Skip/Next controls; every button below carries `widget_info` naming its
+// action, and the "Step N of M" label carries its own, so a screen reader announces both the
+// action and which step it's currently on. Since the controls are laid out in the same fixed
+// order every frame, Tab/Shift+Tab walks Back, Skip for now, then Next/Finish without jumping
+// around.
+use alvr_packets::ServerRequest;
+use eframe::egui::{self, RichText, WidgetInfo, WidgetType};
+
+pub enum SetupWizardRequest {
+    ServerRequest(ServerRequest),
+    Close { finished: bool },
+}
+
+const STEPS: &[(&str, &str)] = &[
+    (
+        "Welcome",
+        "This wizard walks through the one-time setup ALVR needs before you can connect a \
+         headset: registering the SteamVR driver, allowing ALVR through the firewall, and (on \
+         the Steam Deck) adding it as a Steam shortcut.",
+    ),
+    (
+        "Firewall and driver",
+        "Head over to the Installation tab to allow ALVR through the firewall and confirm the \
+         driver is registered with SteamVR. You can come back to this wizard any time from the \
+         same tab.",
+    ),
+    (
+        "Ready",
+        "That's everything. Put on your headset and launch SteamVR to connect.",
+    ),
+];
+
+pub struct SetupWizard {
+    step: usize,
+}
+
+impl SetupWizard {
+    pub fn new() -> Self {
+        Self { step: 0 }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> Option<SetupWizardRequest> {
+        let mut result = None;
+
+        let (title, body) = STEPS[self.step];
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(20.0);
+            ui.heading(RichText::new(title).size(22.0));
+            ui.add_space(10.0);
+            ui.label(body);
+        });
+
+        ui.add_space(20.0);
+
+        ui.horizontal(|ui| {
+            let back_enabled = self.step > 0;
+            let response = ui.add_enabled(back_enabled, egui::Button::new("Back"));
+            if response.clicked() {
+                self.step -= 1;
+            }
+            response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Button, back_enabled, "Back")
+            });
+
+            let response = ui.button("Skip for now");
+            if response.clicked() {
+                result = Some(SetupWizardRequest::Close { finished: false });
+            }
+            response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Button, true, "Skip for now")
+            });
+
+            let is_last_step = self.step == STEPS.len() - 1;
+            let next_label = if is_last_step { "Finish" } else { "Next" };
+            let response = ui.button(next_label);
+            if response.clicked() {
+                if is_last_step {
+                    result = Some(SetupWizardRequest::Close { finished: true });
+                } else {
+                    self.step += 1;
+                }
+            }
+            response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, next_label));
+        });
+
+        ui.add_space(10.0);
+        let progress_text = format!("Step {} of {}", self.step + 1, STEPS.len());
+        let response = ui.label(RichText::new(&progress_text).weak());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, true, progress_text));
+
+        result
+    }
+}
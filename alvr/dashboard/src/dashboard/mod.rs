@@ -12,8 +12,25 @@ use alvr_events::EventType;
 use alvr_gui_common::theme;
 use alvr_packets::{PathValuePair, ServerRequest};
 use alvr_session::SessionConfig;
-use eframe::egui::{self, Align, CentralPanel, Frame, Layout, Margin, RichText, SidePanel, Stroke};
-use std::{collections::BTreeMap, sync::Arc};
+use eframe::egui::{
+    self, Align, CentralPanel, Frame, Layout, Margin, RichText, SidePanel, Stroke, WidgetInfo,
+    WidgetType,
+};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    path::PathBuf,
+    sync::Arc,
+};
+use tracing::instrument;
+
+#[cfg(windows)]
+use crate::firewall;
+#[cfg(target_os = "linux")]
+use crate::steam_shortcut;
+
+// How many recent formatted log lines "Export diagnostics" bundles up, independent of
+// `LogsTab`'s own (much larger) scrollback.
+const DIAGNOSTICS_LOG_RING_SIZE: usize = 500;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 enum Tab {
@@ -45,12 +62,32 @@ pub struct Dashboard {
     new_version_popup: Option<components::NewVersionPopup>,
     setup_wizard_open: bool,
     session: Option<SessionConfig>,
+    connected_to_server_announced: Option<bool>,
+    recent_log_lines: VecDeque<String>,
+    #[cfg(windows)]
+    firewall_task: Arc<Mutex<Option<Result<(), String>>>>,
+    #[cfg(target_os = "linux")]
+    steam_shortcut_task: Arc<Mutex<Option<Result<(), String>>>>,
+    diagnostics_task: Arc<Mutex<Option<Result<PathBuf, String>>>>,
 }
 
 impl Dashboard {
     pub fn new(creation_context: &eframe::CreationContext<'_>, data_sources: DataSources) -> Self {
+        // Without a global default subscriber, every `#[instrument]`/`tracing::info!` emitted by
+        // the dashboard (e.g. `restart_steamvr` below) is simply discarded. Safe to call more
+        // than once per process; later calls are ignored.
+        let _ = tracing_subscriber::fmt()
+            .with_target(false)
+            .without_time()
+            .try_init();
+
         alvr_gui_common::theme::set_theme(&creation_context.egui_ctx);
 
+        // Let the platform integration (AccessKit) build a semantic tree out of the widget
+        // info egui already attaches to each `Response`, so screen readers can drive the
+        // dashboard.
+        creation_context.egui_ctx.enable_accesskit();
+
         data_sources.request(ServerRequest::GetSession);
 
         Self {
@@ -82,11 +119,47 @@ impl Dashboard {
             setup_wizard_open: false,
             session: None,
             new_version_popup: None,
+            connected_to_server_announced: None,
+            recent_log_lines: VecDeque::with_capacity(DIAGNOSTICS_LOG_RING_SIZE),
+            #[cfg(windows)]
+            firewall_task: Arc::new(Mutex::new(None)),
+            #[cfg(target_os = "linux")]
+            steam_shortcut_task: Arc::new(Mutex::new(None)),
+            diagnostics_task: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn push_diagnostics_log_line(&mut self, line: String) {
+        if self.recent_log_lines.len() == DIAGNOSTICS_LOG_RING_SIZE {
+            self.recent_log_lines.pop_front();
         }
+
+        self.recent_log_lines.push_back(line);
+    }
+
+    // Polls a background task's result slot without blocking the UI thread; `None` either
+    // means the task hasn't finished yet or nothing is in flight.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll_task<T>(task: &Arc<Mutex<Option<T>>>) -> Option<T> {
+        task.lock().take()
+    }
+
+    // Push an AccessKit live-region style announcement so screen readers pick up state changes
+    // that don't happen under a user-initiated widget interaction (e.g. an incoming log, or the
+    // SteamVR connection flipping on its own).
+    fn announce(context: &egui::Context, text: impl Into<String>) {
+        context.output_mut(|output| {
+            output.events.push(egui::output::OutputEvent::ValueChanged(
+                WidgetInfo::labeled(WidgetType::Label, true, text.into()),
+            ))
+        });
     }
 
     // This call may block
+    #[instrument(skip_all)]
     fn restart_steamvr(&self, requests: &mut Vec<ServerRequest>) {
+        tracing::info!("Restarting SteamVR");
+
         requests.push(ServerRequest::RestartSteamvr);
 
         let mut server_restarting_lock = self.server_restarting.lock();
@@ -123,8 +196,22 @@ impl eframe::App for Dashboard {
 
             match event.inner.event_type {
                 EventType::Log(log_event) => {
+                    let message = log_event.message.clone();
+                    // Only warnings/errors are worth a screen-reader interruption; announcing
+                    // every routine log line would bury the notifications that actually matter.
+                    let is_notification_worthy = matches!(
+                        log_event.severity,
+                        alvr_events::Severity::Warning | alvr_events::Severity::Error
+                    );
+
+                    self.push_diagnostics_log_line(message.clone());
+
                     self.notification_bar
                         .push_notification(log_event, event.from_dashboard);
+
+                    if is_notification_worthy {
+                        Self::announce(context, message);
+                    }
                 }
                 EventType::GraphStatistics(graph_statistics) => self
                     .statistics_tab
@@ -165,6 +252,21 @@ impl eframe::App for Dashboard {
             }
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            #[cfg(windows)]
+            if let Some(result) = Self::poll_task(&self.firewall_task) {
+                self.installation_tab.notify_firewall_result(result);
+            }
+            #[cfg(target_os = "linux")]
+            if let Some(result) = Self::poll_task(&self.steam_shortcut_task) {
+                self.installation_tab.notify_steam_shortcut_result(result);
+            }
+            if let Some(result) = Self::poll_task(&self.diagnostics_task) {
+                self.installation_tab.notify_diagnostics_exported(result);
+            }
+        }
+
         if *self.server_restarting.lock() {
             CentralPanel::default().show(context, |ui| {
                 // todo: find a way to center both vertically and horizontally
@@ -220,7 +322,11 @@ impl eframe::App for Dashboard {
 
                     ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
                         for (tab, label) in &self.tab_labels {
-                            ui.selectable_value(&mut self.selected_tab, *tab, *label);
+                            let selected = self.selected_tab == *tab;
+                            let response = ui.selectable_value(&mut self.selected_tab, *tab, *label);
+                            response.widget_info(|| {
+                                WidgetInfo::selected(WidgetType::SelectableLabel, true, selected, *label)
+                            });
                         }
                     });
 
@@ -238,23 +344,37 @@ impl eframe::App for Dashboard {
                                 crate::steamvr_launcher::LAUNCHER.lock().launch_steamvr();
                             }
 
+                            if self.connected_to_server_announced != Some(connected_to_server) {
+                                Self::announce(
+                                    context,
+                                    if connected_to_server {
+                                        "SteamVR: Connected"
+                                    } else {
+                                        "SteamVR: Disconnected"
+                                    },
+                                );
+
+                                self.connected_to_server_announced = Some(connected_to_server);
+                            }
+
                             ui.horizontal(|ui| {
                                 ui.add_space(5.0);
                                 ui.label(RichText::new("SteamVR:").size(13.0));
                                 ui.add_space(-10.0);
-                                if connected_to_server {
-                                    ui.label(
-                                        RichText::new("Connected")
-                                            .color(theme::OK_GREEN)
-                                            .size(13.0),
-                                    );
+                                let (text, color) = if connected_to_server {
+                                    ("Connected", theme::OK_GREEN)
                                 } else {
-                                    ui.label(
-                                        RichText::new("Disconnected")
-                                            .color(theme::KO_RED)
-                                            .size(13.0),
-                                    );
-                                }
+                                    ("Disconnected", theme::KO_RED)
+                                };
+
+                                let response = ui.label(RichText::new(text).color(color).size(13.0));
+                                response.widget_info(|| {
+                                    WidgetInfo::labeled(
+                                        WidgetType::Label,
+                                        true,
+                                        format!("SteamVR: {text}"),
+                                    )
+                                });
                             })
                         },
                     )
@@ -289,6 +409,61 @@ impl eframe::App for Dashboard {
                                         ) => {
                                             requests.push(request);
                                         }
+                                        #[cfg(windows)]
+                                        components::InstallationTabRequest::AddFirewallRules => {
+                                            let firewall_task = Arc::clone(&self.firewall_task);
+                                            std::thread::spawn(move || {
+                                                let server_exe = firewall::installed_server_exe();
+                                                let version = firewall::installed_server_version();
+                                                *firewall_task.lock() = Some(firewall::add_rules(
+                                                    &server_exe,
+                                                    &version,
+                                                ));
+                                            });
+                                        }
+                                        #[cfg(windows)]
+                                        components::InstallationTabRequest::RemoveFirewallRules => {
+                                            let firewall_task = Arc::clone(&self.firewall_task);
+                                            std::thread::spawn(move || {
+                                                let version = firewall::installed_server_version();
+                                                *firewall_task.lock() =
+                                                    Some(firewall::remove_rules(&version));
+                                            });
+                                        }
+                                        #[cfg(target_os = "linux")]
+                                        components::InstallationTabRequest::AddSteamShortcut => {
+                                            let steam_shortcut_task =
+                                                Arc::clone(&self.steam_shortcut_task);
+                                            std::thread::spawn(move || {
+                                                *steam_shortcut_task.lock() =
+                                                    Some(steam_shortcut::add_launcher_shortcut());
+                                            });
+                                        }
+                                        #[cfg(target_os = "linux")]
+                                        components::InstallationTabRequest::RemoveSteamShortcut => {
+                                            let steam_shortcut_task =
+                                                Arc::clone(&self.steam_shortcut_task);
+                                            std::thread::spawn(move || {
+                                                *steam_shortcut_task.lock() = Some(
+                                                    steam_shortcut::remove_launcher_shortcut(),
+                                                );
+                                            });
+                                        }
+                                        components::InstallationTabRequest::ExportDiagnostics => {
+                                            let session = self.session.clone();
+                                            let recent_log_lines = self.recent_log_lines.clone();
+                                            let diagnostics_task =
+                                                Arc::clone(&self.diagnostics_task);
+                                            std::thread::spawn(move || {
+                                                let result = crate::diagnostics::export_bundle(
+                                                    session.as_ref(),
+                                                    connected_to_server,
+                                                    &recent_log_lines,
+                                                );
+
+                                                *diagnostics_task.lock() = Some(result);
+                                            });
+                                        }
                                     }
                                 }
                             }
@@ -0,0 +1,119 @@
+// Bundles the state support usually has to ask for one piece at a time — recent logs, the
+// session config, and the SteamVR connection status — into a single zip, so "paste your logs"
+// becomes "attach this file".
+use alvr_session::SessionConfig;
+use std::{
+    collections::VecDeque,
+    io::{Seek, Write},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+fn diagnostics_dir() -> PathBuf {
+    dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(std::env::temp_dir)
+}
+
+// Writes the bundle's contents to any `Write + Seek` target, independent of where the final
+// file ends up. Kept separate from `export_bundle` so the zip contents can be asserted on in
+// tests without touching the real filesystem.
+fn write_bundle(
+    writer: impl Write + Seek,
+    session: Option<&SessionConfig>,
+    connected_to_server: bool,
+    recent_log_lines: &VecDeque<String>,
+    timestamp: u64,
+) -> Result<(), String> {
+    let mut zip = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default();
+
+    zip.start_file("status.txt", options)
+        .map_err(|e| e.to_string())?;
+    writeln!(
+        zip,
+        "SteamVR connected: {connected_to_server}\nGenerated at unix time: {timestamp}"
+    )
+    .map_err(|e| e.to_string())?;
+
+    zip.start_file("session.json", options)
+        .map_err(|e| e.to_string())?;
+    let session_json = match session {
+        Some(session) => serde_json::to_string_pretty(session).map_err(|e| e.to_string())?,
+        None => "null".to_owned(),
+    };
+    zip.write_all(session_json.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    zip.start_file("logs.txt", options)
+        .map_err(|e| e.to_string())?;
+    for line in recent_log_lines {
+        writeln!(zip, "{line}").map_err(|e| e.to_string())?;
+    }
+
+    zip.finish().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub fn export_bundle(
+    session: Option<&SessionConfig>,
+    connected_to_server: bool,
+    recent_log_lines: &VecDeque<String>,
+) -> Result<PathBuf, String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+
+    let destination = diagnostics_dir().join(format!("alvr_diagnostics_{timestamp}.zip"));
+
+    let file = std::fs::File::create(&destination).map_err(|e| e.to_string())?;
+    write_bundle(file, session, connected_to_server, recent_log_lines, timestamp)?;
+
+    Ok(destination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn bundle_contains_status_and_log_files_with_expected_content() {
+        let mut recent_log_lines = VecDeque::new();
+        recent_log_lines.push_back("first line".to_owned());
+        recent_log_lines.push_back("second line".to_owned());
+
+        let mut buf = Cursor::new(vec![]);
+        write_bundle(&mut buf, None, true, &recent_log_lines, 1_700_000_000).unwrap();
+
+        let mut archive = zip::ZipArchive::new(buf).unwrap();
+
+        let mut status = String::new();
+        archive
+            .by_name("status.txt")
+            .unwrap()
+            .read_to_string(&mut status)
+            .unwrap();
+        assert!(status.contains("SteamVR connected: true"));
+        assert!(status.contains("1700000000"));
+
+        let mut session_json = String::new();
+        archive
+            .by_name("session.json")
+            .unwrap()
+            .read_to_string(&mut session_json)
+            .unwrap();
+        assert_eq!(session_json, "null");
+
+        let mut logs = String::new();
+        archive
+            .by_name("logs.txt")
+            .unwrap()
+            .read_to_string(&mut logs)
+            .unwrap();
+        assert_eq!(logs, "first line\nsecond line\n");
+    }
+}
@@ -0,0 +1,142 @@
+// Windows Firewall rule management for the installed ALVR server executable. Rules are keyed
+// on the installed server version (not the dashboard's own build version, which can lag behind
+// if the server gets updated independently) so re-registering doesn't leave stale duplicates,
+// and `add_rules`/`remove_rules` are both safe to call repeatedly.
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// The dashboard and server binaries are installed side by side (see `install_dir` in the
+// launcher's `actions` module); the dashboard's own `current_exe` is a different binary and
+// must not be used here.
+fn install_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("alvr")
+}
+
+pub fn installed_server_exe() -> PathBuf {
+    install_dir().join("ALVR Server.exe")
+}
+
+// Best-effort: the launcher drops a plain text marker next to the server binary when it
+// installs a release. Falls back to "unknown" so a missing marker doesn't crash the firewall
+// flow, it just won't dedupe against a previous rule for the same version.
+pub fn installed_server_version() -> String {
+    std::fs::read_to_string(install_dir().join("version.txt"))
+        .map(|version| version.trim().to_owned())
+        .unwrap_or_else(|_| "unknown".to_owned())
+}
+
+fn rule_name(version: &str) -> String {
+    format!("ALVR Server {version}")
+}
+
+fn rule_exists(direction: &str, version: &str) -> bool {
+    Command::new("netsh")
+        .args([
+            "advfirewall",
+            "firewall",
+            "show",
+            "rule",
+            &format!("name={} ({direction})", rule_name(version)),
+        ])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+fn add_rule(
+    direction: &str,
+    netsh_direction: &str,
+    server_exe: &Path,
+    version: &str,
+) -> Result<(), String> {
+    if rule_exists(direction, version) {
+        return Ok(());
+    }
+
+    let status = Command::new("netsh")
+        .args([
+            "advfirewall",
+            "firewall",
+            "add",
+            "rule",
+            &format!("name={} ({direction})", rule_name(version)),
+            &format!("dir={netsh_direction}"),
+            "action=allow",
+            &format!("program={}", server_exe.display()),
+            "enable=yes",
+        ])
+        .status()
+        .map_err(|e| e.to_string())?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("netsh exited with status {status}"))
+    }
+}
+
+fn remove_rule(direction: &str, version: &str) -> Result<(), String> {
+    if !rule_exists(direction, version) {
+        return Ok(());
+    }
+
+    let status = Command::new("netsh")
+        .args([
+            "advfirewall",
+            "firewall",
+            "delete",
+            "rule",
+            &format!("name={} ({direction})", rule_name(version)),
+        ])
+        .status()
+        .map_err(|e| e.to_string())?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("netsh exited with status {status}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_name_keys_on_version_so_different_installs_dont_collide() {
+        assert_eq!(rule_name("20.11.1"), "ALVR Server 20.11.1");
+        assert_ne!(rule_name("20.11.1"), rule_name("20.12.0"));
+    }
+
+    #[test]
+    fn installed_server_version_falls_back_to_unknown_without_a_marker() {
+        // `install_dir` always resolves under a real data/temp directory that (in a clean test
+        // environment) won't have an ALVR `version.txt` sitting in it, so this also exercises
+        // the "marker genuinely missing" path the firewall flow has to tolerate.
+        let version = installed_server_version();
+        assert_eq!(version, "unknown");
+    }
+}
+
+// Registers both the inbound and outbound rules for `server_exe` at `version`. Idempotent:
+// calling this again (e.g. the user clicking the button twice, or a reinstall at the same
+// version) leaves the existing rules untouched.
+pub fn add_rules(server_exe: &Path, version: &str) -> Result<(), String> {
+    add_rule("in", "in", server_exe, version)?;
+    add_rule("out", "out", server_exe, version)?;
+
+    Ok(())
+}
+
+// Removes both rules for `version`. Only the Installation tab's "Remove firewall rules" button
+// calls this; nothing removes the rules automatically on uninstall.
+pub fn remove_rules(version: &str) -> Result<(), String> {
+    remove_rule("in", version)?;
+    remove_rule("out", version)?;
+
+    Ok(())
+}
@@ -0,0 +1,367 @@
+// Reads and rewrites Steam's binary `shortcuts.vdf` so ALVR can add/remove itself as a
+// non-Steam game, which is what makes it launchable from Gaming Mode on the Steam Deck.
+//
+// The format is a minimal binary VDF: each "object" is a run of typed fields terminated by a
+// 0x08 byte. We only need to round-trip entries we don't understand (icons, tags, launch
+// options set by Steam itself), so each shortcut is kept as an ordered list of raw fields
+// instead of a fixed struct.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, PartialEq, Debug)]
+enum VdfField {
+    String(String),
+    Int32(i32),
+    // A nested object (e.g. the `tags` map), kept as its own field list.
+    Object(Vec<(String, VdfField)>),
+}
+
+type Shortcut = Vec<(String, VdfField)>;
+
+const TYPE_OBJECT: u8 = 0x00;
+const TYPE_STRING: u8 = 0x01;
+const TYPE_INT32: u8 = 0x02;
+const TYPE_END: u8 = 0x08;
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        let b = *self.bytes.get(self.pos)?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn read_i32(&mut self) -> Option<i32> {
+        let slice = self.bytes.get(self.pos..self.pos + 4)?;
+        self.pos += 4;
+        Some(i32::from_le_bytes(slice.try_into().ok()?))
+    }
+
+    fn read_cstring(&mut self) -> Option<String> {
+        let start = self.pos;
+        while *self.bytes.get(self.pos)? != 0 {
+            self.pos += 1;
+        }
+        let s = String::from_utf8_lossy(&self.bytes[start..self.pos]).into_owned();
+        self.pos += 1; // skip the null terminator
+        Some(s)
+    }
+
+    // Reads fields until a TYPE_END marker (or end of input) is hit.
+    fn read_object_fields(&mut self) -> Vec<(String, VdfField)> {
+        let mut fields = vec![];
+
+        while let Some(field_type) = self.read_u8() {
+            if field_type == TYPE_END {
+                break;
+            }
+
+            let Some(name) = self.read_cstring() else {
+                break;
+            };
+
+            let value = match field_type {
+                TYPE_OBJECT => VdfField::Object(self.read_object_fields()),
+                TYPE_STRING => match self.read_cstring() {
+                    Some(s) => VdfField::String(s),
+                    None => break,
+                },
+                TYPE_INT32 => match self.read_i32() {
+                    Some(n) => VdfField::Int32(n),
+                    None => break,
+                },
+                _ => break,
+            };
+
+            fields.push((name, value));
+        }
+
+        fields
+    }
+}
+
+fn parse_shortcuts(bytes: &[u8]) -> Vec<Shortcut> {
+    let mut reader = Reader::new(bytes);
+
+    // Top-level is a single "shortcuts" object whose fields are each numbered (as strings)
+    // shortcut objects.
+    let Some(TYPE_OBJECT) = reader.read_u8() else {
+        return vec![];
+    };
+    let Some(_name) = reader.read_cstring() else {
+        return vec![];
+    };
+
+    reader
+        .read_object_fields()
+        .into_iter()
+        .filter_map(|(_, field)| match field {
+            VdfField::Object(fields) => Some(fields),
+            _ => None,
+        })
+        .collect()
+}
+
+fn write_cstring(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(s.as_bytes());
+    buf.push(0);
+}
+
+fn write_fields(buf: &mut Vec<u8>, fields: &[(String, VdfField)]) {
+    for (name, value) in fields {
+        match value {
+            VdfField::String(s) => {
+                buf.push(TYPE_STRING);
+                write_cstring(buf, name);
+                write_cstring(buf, s);
+            }
+            VdfField::Int32(n) => {
+                buf.push(TYPE_INT32);
+                write_cstring(buf, name);
+                buf.extend_from_slice(&n.to_le_bytes());
+            }
+            VdfField::Object(fields) => {
+                buf.push(TYPE_OBJECT);
+                write_cstring(buf, name);
+                write_fields(buf, fields);
+                buf.push(TYPE_END);
+            }
+        }
+    }
+}
+
+fn serialize_shortcuts(shortcuts: &[Shortcut]) -> Vec<u8> {
+    let mut buf = vec![];
+
+    buf.push(TYPE_OBJECT);
+    write_cstring(&mut buf, "shortcuts");
+
+    for (index, shortcut) in shortcuts.iter().enumerate() {
+        buf.push(TYPE_OBJECT);
+        write_cstring(&mut buf, &index.to_string());
+        write_fields(&mut buf, shortcut);
+        buf.push(TYPE_END);
+    }
+
+    buf.push(TYPE_END); // end "shortcuts"
+
+    buf
+}
+
+fn field_string<'a>(shortcut: &'a Shortcut, name: &str) -> Option<&'a str> {
+    shortcut.iter().find_map(|(n, v)| match v {
+        VdfField::String(s) if n == name => Some(s.as_str()),
+        _ => None,
+    })
+}
+
+fn is_same_entry(shortcut: &Shortcut, app_name: &str, exe: &str) -> bool {
+    field_string(shortcut, "AppName") == Some(app_name) && field_string(shortcut, "Exe") == Some(exe)
+}
+
+fn build_entry(app_name: &str, exe: &str, start_dir: &str, icon: &str) -> Shortcut {
+    vec![
+        ("AppName".into(), VdfField::String(app_name.into())),
+        ("Exe".into(), VdfField::String(exe.into())),
+        ("StartDir".into(), VdfField::String(start_dir.into())),
+        ("icon".into(), VdfField::String(icon.into())),
+        ("ShortcutPath".into(), VdfField::String(String::new())),
+        ("LaunchOptions".into(), VdfField::String(String::new())),
+        ("IsHidden".into(), VdfField::Int32(0)),
+        ("AllowDesktopConfig".into(), VdfField::Int32(1)),
+        ("AllowOverlay".into(), VdfField::Int32(1)),
+        ("OpenVR".into(), VdfField::Int32(0)),
+        ("Devkit".into(), VdfField::Int32(0)),
+        ("DevkitGameID".into(), VdfField::String(String::new())),
+        ("LastPlayTime".into(), VdfField::Int32(0)),
+        ("tags".into(), VdfField::Object(vec![])),
+    ]
+}
+
+fn read_shortcuts_file(path: &Path) -> Vec<Shortcut> {
+    match fs::read(path) {
+        Ok(bytes) => parse_shortcuts(&bytes),
+        Err(_) => vec![],
+    }
+}
+
+fn write_shortcuts_file(path: &Path, shortcuts: &[Shortcut]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, serialize_shortcuts(shortcuts))
+}
+
+// Paths of all local Steam users' `shortcuts.vdf`. Nothing is created here; the file and its
+// `config` folder may not exist yet (`write_shortcuts_file` creates them).
+fn shortcuts_vdf_paths() -> Vec<PathBuf> {
+    let Some(home) = dirs::home_dir() else {
+        return vec![];
+    };
+
+    let userdata = home.join(".steam/steam/userdata");
+
+    let Ok(entries) = fs::read_dir(&userdata) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.path().join("config/shortcuts.vdf"))
+        .collect()
+}
+
+// Adds the non-Steam shortcut entry for every local Steam user. Idempotent:
+// re-running with the same `exe`/`app_name` leaves the existing entry untouched.
+pub fn add_shortcut(app_name: &str, exe: &str, start_dir: &str, icon: &str) -> Result<(), String> {
+    for path in shortcuts_vdf_paths() {
+        let mut shortcuts = read_shortcuts_file(&path);
+
+        if !shortcuts
+            .iter()
+            .any(|shortcut| is_same_entry(shortcut, app_name, exe))
+        {
+            shortcuts.push(build_entry(app_name, exe, start_dir, icon));
+            write_shortcuts_file(&path, &shortcuts).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+// Removes the shortcut entry for every local Steam user, if present.
+pub fn remove_shortcut(app_name: &str, exe: &str) -> Result<(), String> {
+    for path in shortcuts_vdf_paths() {
+        let shortcuts = read_shortcuts_file(&path);
+        let filtered = shortcuts
+            .into_iter()
+            .filter(|shortcut| !is_same_entry(shortcut, app_name, exe))
+            .collect::<Vec<_>>();
+
+        write_shortcuts_file(&path, &filtered).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+const APP_NAME: &str = "ALVR";
+
+// Writes the bundled `dashboard.ico` to `~/.local/share/alvr` and returns its path for the
+// shortcut's `icon` field; best-effort, an empty path just means Steam falls back to a generic
+// icon.
+fn copy_launcher_icon() -> String {
+    let Some(home) = dirs::home_dir() else {
+        return String::new();
+    };
+
+    let destination = home.join(".local/share/alvr/dashboard.ico");
+
+    let Some(parent) = destination.parent() else {
+        return String::new();
+    };
+    if fs::create_dir_all(parent).is_err() {
+        return String::new();
+    }
+
+    match fs::write(
+        &destination,
+        include_bytes!("../../dashboard/resources/dashboard.ico"),
+    ) {
+        Ok(()) => destination.to_string_lossy().into_owned(),
+        Err(_) => String::new(),
+    }
+}
+
+// The executable of the running process, i.e. the dashboard itself. The shortcut points at
+// this binary, not at the separate ALVR launcher binary.
+fn launcher_exe() -> Result<PathBuf, String> {
+    std::env::current_exe().map_err(|e| e.to_string())
+}
+
+// Adds the running ALVR dashboard as a non-Steam game for every local Steam user.
+pub fn add_launcher_shortcut() -> Result<(), String> {
+    let exe = launcher_exe()?;
+    let start_dir = exe
+        .parent()
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let icon = copy_launcher_icon();
+
+    add_shortcut(APP_NAME, &exe.to_string_lossy(), &start_dir, &icon)
+}
+
+// Removes the running ALVR dashboard's non-Steam shortcut for every local Steam user.
+pub fn remove_launcher_shortcut() -> Result<(), String> {
+    let exe = launcher_exe()?;
+
+    remove_shortcut(APP_NAME, &exe.to_string_lossy())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_a_single_shortcut() {
+        let shortcuts = vec![build_entry(
+            "ALVR",
+            "/home/deck/alvr_launcher",
+            "/home/deck",
+            "/home/deck/.local/share/alvr/dashboard.ico",
+        )];
+
+        let bytes = serialize_shortcuts(&shortcuts);
+
+        assert_eq!(parse_shortcuts(&bytes), shortcuts);
+    }
+
+    // The round-trip tests in this module only prove `parse(serialize(x)) == x` for this module's own
+    // encoder/decoder, which can't catch a terminator-count mismatch against the real binary
+    // format Steam itself writes and reads — getting that wrong in either direction risks
+    // corrupting a user's actual shortcuts.vdf. This pins down the exact byte-level structure
+    // instead: one empty `tags` object plus one shortcut plus the top-level "shortcuts" object
+    // should close with exactly three consecutive TYPE_END bytes and no others. Still not a
+    // substitute for diffing against (or loading) a file Steam itself produced, which isn't
+    // possible in this environment.
+    #[test]
+    fn serialized_output_has_exactly_one_terminator_per_object() {
+        let shortcuts = vec![build_entry("ALVR", "/home/deck/alvr_launcher", "/home/deck", "")];
+
+        let bytes = serialize_shortcuts(&shortcuts);
+
+        assert_eq!(&bytes[bytes.len() - 3..], &[TYPE_END, TYPE_END, TYPE_END]);
+        assert_eq!(bytes.iter().filter(|&&b| b == TYPE_END).count(), 3);
+    }
+
+    #[test]
+    fn round_trips_multiple_shortcuts_and_preserves_unknown_fields() {
+        let mut other_game = build_entry("Other Game", "/home/deck/other_game", "/home/deck", "");
+        other_game.push(("LaunchOptions".into(), VdfField::String("--fullscreen".into())));
+
+        let shortcuts = vec![
+            build_entry(
+                "ALVR",
+                "/home/deck/alvr_launcher",
+                "/home/deck",
+                "/home/deck/.local/share/alvr/dashboard.ico",
+            ),
+            other_game,
+        ];
+
+        let bytes = serialize_shortcuts(&shortcuts);
+
+        assert_eq!(parse_shortcuts(&bytes), shortcuts);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::Progress;
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+// Commands the UI thread can send to an in-flight download.
+pub enum DownloadControl {
+    Pause,
+    Resume,
+    Cancel,
+    SetSpeedLimit(Option<u64>),
+}
+
+// After this many consecutive dropped connections with no bytes read in between, give up
+// instead of hot-looping re-requests against a server that never sends anything.
+const MAX_CONSECUTIVE_EMPTY_DROPS: u32 = 10;
+
+// Downloads `url` to `destination`, resuming from a `.part` file left over by a previous
+// attempt. When the server reports a length, the file is only renamed to its final name once
+// that length has been written in full; without a Content-Length it is renamed as soon as the
+// stream ends, with no length check. `progress_callback` is invoked with the running `Progress` after every
+// chunk; `control_receiver` lets the caller pause/resume/cancel or change the speed cap
+// mid-download.
+pub fn download_resumable(
+    url: &str,
+    destination: &Path,
+    mut speed_limit_bytes_per_sec: Option<u64>,
+    control_receiver: &mpsc::Receiver<DownloadControl>,
+    mut progress_callback: impl FnMut(Progress),
+) -> Result<(), String> {
+    let part_path = part_path(destination);
+
+    let mut downloaded_bytes = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+
+    if downloaded_bytes > 0 {
+        tracing::info!(downloaded_bytes, "Resuming partial download");
+    }
+
+    let mut paused = false;
+    let mut window_start = Instant::now();
+    let mut bytes_in_window = 0_u64;
+    let mut consecutive_empty_drops = 0_u32;
+
+    loop {
+        let downloaded_bytes_at_attempt_start = downloaded_bytes;
+
+        let response = ureq::get(url)
+            .set("Range", &format!("bytes={downloaded_bytes}-"))
+            .call()
+            .map_err(|e| format!("Failed to request {url}: {e}"))?;
+
+        // If we asked for a range but got a full 200 back, the server/CDN ignored the `Range`
+        // header: restart from scratch instead of writing the full body at the resume offset,
+        // which would corrupt the `.part` file.
+        if downloaded_bytes > 0 && response.status() != 206 {
+            tracing::warn!("Server ignored Range header; restarting download from byte 0");
+            downloaded_bytes = 0;
+        }
+
+        let content_length = response
+            .header("Content-Length")
+            .and_then(|len| len.parse::<u64>().ok());
+        let total_bytes = total_length(response.status(), content_length, downloaded_bytes);
+
+        let mut part_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(downloaded_bytes == 0)
+            .open(&part_path)
+            .map_err(|e| format!("Failed to open {}: {e}", part_path.display()))?;
+        part_file
+            .seek(SeekFrom::Start(downloaded_bytes))
+            .map_err(|e| e.to_string())?;
+
+        let mut reader = response.into_reader();
+        let mut buf = [0_u8; 64 * 1024];
+
+        loop {
+            if let Some(action) =
+                drain_controls(control_receiver, &mut paused, &mut speed_limit_bytes_per_sec)
+            {
+                return action;
+            }
+
+            while paused {
+                std::thread::sleep(Duration::from_millis(100));
+
+                if let Some(action) =
+                    drain_controls(control_receiver, &mut paused, &mut speed_limit_bytes_per_sec)
+                {
+                    return action;
+                }
+            }
+
+            let read_bytes = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                // The connection dropped mid-stream: break out and retry with an updated
+                // Range header instead of failing the whole download.
+                Err(e) => {
+                    tracing::warn!(error = %e, downloaded_bytes, "Connection dropped mid-download, retrying");
+                    break;
+                }
+            };
+
+            part_file
+                .write_all(&buf[..read_bytes])
+                .map_err(|e| e.to_string())?;
+
+            downloaded_bytes += read_bytes as u64;
+            bytes_in_window += read_bytes as u64;
+
+            let elapsed = window_start.elapsed();
+            let bytes_per_sec = if elapsed.as_secs_f32() > 0.0 {
+                bytes_in_window as f32 / elapsed.as_secs_f32()
+            } else {
+                0.0
+            };
+
+            if let Some(delay) =
+                rate_limit_delay(bytes_in_window, elapsed, speed_limit_bytes_per_sec)
+            {
+                std::thread::sleep(delay);
+            }
+
+            if elapsed >= Duration::from_millis(500) {
+                window_start = Instant::now();
+                bytes_in_window = 0;
+            }
+
+            progress_callback(Progress {
+                message: format!("Downloading {}", destination_name(destination)),
+                progress: total_bytes
+                    .map(|total| downloaded_bytes as f32 / total as f32)
+                    .unwrap_or(0.0),
+                downloaded_bytes,
+                total_bytes,
+                bytes_per_sec,
+            });
+        }
+
+        if total_bytes.is_none_or(|total| downloaded_bytes >= total) {
+            break;
+        }
+
+        // Otherwise the connection was dropped before the expected length was reached: loop
+        // around and re-request the remainder. If the server keeps dropping us without ever
+        // making progress, back off and eventually give up instead of hot-looping requests.
+        if downloaded_bytes == downloaded_bytes_at_attempt_start {
+            consecutive_empty_drops += 1;
+
+            if consecutive_empty_drops >= MAX_CONSECUTIVE_EMPTY_DROPS {
+                return Err(format!(
+                    "Giving up after {consecutive_empty_drops} consecutive dropped connections with no progress"
+                ));
+            }
+
+            std::thread::sleep(Duration::from_millis(200) * consecutive_empty_drops);
+        } else {
+            consecutive_empty_drops = 0;
+        }
+    }
+
+    fs::rename(&part_path, destination)
+        .map_err(|e| format!("Failed to finalize {}: {e}", destination.display()))?;
+
+    tracing::info!(downloaded_bytes, "Download finalized");
+
+    Ok(())
+}
+
+// Applies any pending pause/resume/speed-limit messages and returns `Some` only when the
+// download should stop (cancellation).
+fn drain_controls(
+    control_receiver: &mpsc::Receiver<DownloadControl>,
+    paused: &mut bool,
+    speed_limit_bytes_per_sec: &mut Option<u64>,
+) -> Option<Result<(), String>> {
+    while let Ok(control) = control_receiver.try_recv() {
+        match control {
+            DownloadControl::Pause => *paused = true,
+            DownloadControl::Resume => *paused = false,
+            DownloadControl::Cancel => return Some(Err("Download cancelled".into())),
+            DownloadControl::SetSpeedLimit(limit) => *speed_limit_bytes_per_sec = limit,
+        }
+    }
+
+    None
+}
+
+fn part_path(destination: &Path) -> PathBuf {
+    let mut part = destination.as_os_str().to_owned();
+    part.push(".part");
+    PathBuf::from(part)
+}
+
+fn destination_name(destination: &Path) -> String {
+    destination
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+// How long to sleep before allowing more reads, given how many bytes have been read so far in
+// the current window and how long that window has been open. Returns `None` when there's no
+// cap, or the cap is 0 (not a meaningful rate limit, and would make `from_secs_f64` panic on a
+// non-finite value below), in which case reads should proceed unthrottled.
+fn rate_limit_delay(
+    bytes_in_window: u64,
+    elapsed: Duration,
+    speed_limit_bytes_per_sec: Option<u64>,
+) -> Option<Duration> {
+    let limit = speed_limit_bytes_per_sec.filter(|&limit| limit > 0)?;
+    let budgeted = Duration::from_secs_f64(bytes_in_window as f64 / limit as f64);
+    budgeted.checked_sub(elapsed).filter(|delay| !delay.is_zero())
+}
+
+fn total_length(status: u16, content_length: Option<u64>, downloaded_bytes: u64) -> Option<u64> {
+    if status == 206 {
+        // Partial content: Content-Length is the size of the remaining bytes only.
+        content_length.map(|remaining| downloaded_bytes + remaining)
+    } else {
+        content_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_length_adds_downloaded_bytes_for_partial_content() {
+        assert_eq!(total_length(206, Some(500), 1500), Some(2000));
+    }
+
+    #[test]
+    fn total_length_ignores_downloaded_bytes_for_full_content() {
+        assert_eq!(total_length(200, Some(2000), 1500), Some(2000));
+    }
+
+    #[test]
+    fn total_length_is_none_without_a_content_length_header() {
+        assert_eq!(total_length(206, None, 1500), None);
+        assert_eq!(total_length(200, None, 0), None);
+    }
+
+    #[test]
+    fn drain_controls_applies_pause_resume_and_speed_limit() {
+        let (sender, receiver) = mpsc::channel();
+        let mut paused = false;
+        let mut speed_limit = None;
+
+        sender.send(DownloadControl::Pause).unwrap();
+        sender.send(DownloadControl::SetSpeedLimit(Some(1024))).unwrap();
+        assert!(drain_controls(&receiver, &mut paused, &mut speed_limit).is_none());
+        assert!(paused);
+        assert_eq!(speed_limit, Some(1024));
+
+        sender.send(DownloadControl::Resume).unwrap();
+        assert!(drain_controls(&receiver, &mut paused, &mut speed_limit).is_none());
+        assert!(!paused);
+    }
+
+    #[test]
+    fn rate_limit_delay_is_none_without_a_limit() {
+        assert_eq!(rate_limit_delay(1024, Duration::from_millis(10), None), None);
+    }
+
+    #[test]
+    fn rate_limit_delay_is_none_for_a_zero_limit() {
+        assert_eq!(
+            rate_limit_delay(1024, Duration::from_millis(10), Some(0)),
+            None
+        );
+    }
+
+    #[test]
+    fn rate_limit_delay_is_none_when_under_budget() {
+        // 1024 bytes in 500ms is 2048 bytes/sec, under the 4096 bytes/sec cap.
+        assert_eq!(
+            rate_limit_delay(1024, Duration::from_millis(500), Some(4096)),
+            None
+        );
+    }
+
+    #[test]
+    fn rate_limit_delay_sleeps_when_over_budget() {
+        // 1024 bytes at a 1024 bytes/sec cap should take a full second; only 100ms has
+        // elapsed, so we should be told to sleep the remaining 900ms.
+        assert_eq!(
+            rate_limit_delay(1024, Duration::from_millis(100), Some(1024)),
+            Some(Duration::from_millis(900))
+        );
+    }
+
+    #[test]
+    fn drain_controls_stops_on_cancel() {
+        let (sender, receiver) = mpsc::channel();
+        let mut paused = false;
+        let mut speed_limit = None;
+
+        sender.send(DownloadControl::Cancel).unwrap();
+
+        assert!(
+            drain_controls(&receiver, &mut paused, &mut speed_limit)
+                .unwrap()
+                .is_err()
+        );
+    }
+}
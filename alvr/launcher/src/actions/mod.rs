@@ -0,0 +1,230 @@
+mod download;
+mod tracing_bridge;
+
+use crate::{ReleaseChannelsInfo, ReleaseInfo, UiMessage, WorkerMessage};
+use download::DownloadControl;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+};
+use tracing::instrument;
+
+fn install_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("alvr")
+}
+
+// Names of the two platform server archives, as opposed to the client APK, which doesn't get
+// extracted or firewall-versioned.
+const SERVER_ASSET_NAMES: &[&str] = &["windows.zip", "linux.zip"];
+
+// Unpacks the downloaded server archive into `install_dir` and drops a `version.txt` marker
+// next to it, which is what `firewall::installed_server_version` reads to key its rules to the
+// currently-installed version instead of always reporting "unknown".
+fn extract_server_archive(archive_path: &Path, version: &str) -> Result<(), String> {
+    let file = fs::File::open(archive_path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+    archive
+        .extract(install_dir())
+        .map_err(|e| format!("Failed to extract {}: {e}", archive_path.display()))?;
+
+    fs::write(install_dir().join("version.txt"), version).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Downloads and installs the given asset, reporting progress back to the UI thread.
+#[instrument(skip(release_info, worker_message_sender, control_receiver), fields(version = %release_info.version))]
+fn install_release(
+    release_info: &ReleaseInfo,
+    asset_name: &str,
+    worker_message_sender: &Sender<WorkerMessage>,
+    control_receiver: &Receiver<DownloadControl>,
+    speed_limit_bytes_per_sec: Option<u64>,
+) -> Result<PathBuf, String> {
+    let url = release_info.assets.get(asset_name).ok_or_else(|| {
+        format!(
+            "No asset named {asset_name} for version {}",
+            release_info.version
+        )
+    })?;
+
+    let destination = install_dir().join(asset_name);
+
+    tracing::info!(asset_name, %url, "Starting download");
+
+    download::download_resumable(
+        url,
+        &destination,
+        speed_limit_bytes_per_sec,
+        control_receiver,
+        |progress| {
+            worker_message_sender
+                .send(WorkerMessage::ProgressUpdate(progress))
+                .ok();
+        },
+    )?;
+
+    tracing::info!(destination = %destination.display(), "Install step finished");
+
+    if SERVER_ASSET_NAMES.contains(&asset_name) {
+        tracing::info!("Extracting server archive");
+        extract_server_archive(&destination, &release_info.version)?;
+    }
+
+    Ok(destination)
+}
+
+// Spawns the download+install on its own thread so the worker loop stays responsive to
+// pause/resume/cancel messages arriving on `ui_message_receiver` while a transfer is in
+// flight. Returns the sender half of the new download's control channel.
+#[instrument(skip(release_info, worker_message_sender), fields(version = %release_info.version))]
+fn spawn_install(
+    release_info: ReleaseInfo,
+    asset_name: &'static str,
+    worker_message_sender: Sender<WorkerMessage>,
+    speed_limit_bytes_per_sec: Option<u64>,
+) -> Sender<DownloadControl> {
+    let (control_sender, control_receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let result = install_release(
+            &release_info,
+            asset_name,
+            &worker_message_sender,
+            &control_receiver,
+            speed_limit_bytes_per_sec,
+        );
+
+        match result {
+            Ok(_) => worker_message_sender.send(WorkerMessage::Done).ok(),
+            Err(e) => worker_message_sender.send(WorkerMessage::Error(e)).ok(),
+        };
+    });
+
+    control_sender
+}
+
+const RELEASES_URL: &str = "https://api.github.com/repos/alvr-org/ALVR/releases";
+
+#[derive(serde::Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    prerelease: bool,
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(serde::Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+// Stable and nightly are just a prerelease-flag split of the same GitHub releases feed.
+#[instrument]
+fn fetch_release_channels() -> Result<ReleaseChannelsInfo, String> {
+    tracing::info!("Resolving release channels");
+
+    let releases: Vec<GithubRelease> = ureq::get(RELEASES_URL)
+        .set("User-Agent", "alvr-launcher")
+        .call()
+        .map_err(|e| format!("Failed to fetch releases: {e}"))?
+        .into_json()
+        .map_err(|e| format!("Failed to parse releases: {e}"))?;
+
+    let mut stable = vec![];
+    let mut nightly = vec![];
+
+    for release in releases {
+        let release_info = ReleaseInfo {
+            version: release.tag_name.trim_start_matches('v').to_owned(),
+            assets: release
+                .assets
+                .into_iter()
+                .map(|asset| (asset.name, asset.browser_download_url))
+                .collect(),
+        };
+
+        if release.prerelease {
+            nightly.push(release_info);
+        } else {
+            stable.push(release_info);
+        }
+    }
+
+    Ok(ReleaseChannelsInfo { stable, nightly })
+}
+
+pub fn worker(
+    ui_message_receiver: Receiver<UiMessage>,
+    worker_message_sender: Sender<WorkerMessage>,
+) {
+    tracing_bridge::init(worker_message_sender.clone());
+
+    match fetch_release_channels() {
+        Ok(info) => worker_message_sender
+            .send(WorkerMessage::ReleaseChannelsInfo(info))
+            .ok(),
+        Err(e) => worker_message_sender.send(WorkerMessage::Error(e)).ok(),
+    };
+
+    let mut speed_limit_bytes_per_sec = None;
+    let mut active_download_control: Option<Sender<DownloadControl>> = None;
+
+    while let Ok(message) = ui_message_receiver.recv() {
+        match message {
+            UiMessage::InstallServer {
+                release_info,
+                session_version: _,
+            } => {
+                let asset_name = if cfg!(windows) {
+                    "windows.zip"
+                } else {
+                    "linux.zip"
+                };
+
+                active_download_control = Some(spawn_install(
+                    release_info,
+                    asset_name,
+                    worker_message_sender.clone(),
+                    speed_limit_bytes_per_sec,
+                ));
+            }
+            UiMessage::InstallClient(release_info) => {
+                active_download_control = Some(spawn_install(
+                    release_info,
+                    "alvr_client_android.apk",
+                    worker_message_sender.clone(),
+                    speed_limit_bytes_per_sec,
+                ));
+            }
+            UiMessage::SetDownloadSpeedLimit(limit) => {
+                speed_limit_bytes_per_sec = limit;
+
+                if let Some(control) = &active_download_control {
+                    control.send(DownloadControl::SetSpeedLimit(limit)).ok();
+                }
+            }
+            UiMessage::PauseDownload => {
+                if let Some(control) = &active_download_control {
+                    control.send(DownloadControl::Pause).ok();
+                }
+            }
+            UiMessage::ResumeDownload => {
+                if let Some(control) = &active_download_control {
+                    control.send(DownloadControl::Resume).ok();
+                }
+            }
+            UiMessage::CancelDownload => {
+                if let Some(control) = active_download_control.take() {
+                    control.send(DownloadControl::Cancel).ok();
+                }
+            }
+            UiMessage::Quit => break,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// Routes `tracing` events emitted on the worker thread through the existing `WorkerMessage`
+// channel as `WorkerMessage::Log` lines instead of stderr. The launcher UI collects them in
+// `Launcher::log_lines` but does not display them, and they do not reach the dashboard's
+// `LogsTab`, which runs in a separate process.
+use crate::WorkerMessage;
+use std::{
+    io,
+    sync::mpsc::Sender,
+};
+
+#[derive(Clone)]
+struct ChannelWriter {
+    worker_message_sender: Sender<WorkerMessage>,
+}
+
+impl io::Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let line = String::from_utf8_lossy(buf).trim_end().to_owned();
+
+        if !line.is_empty() {
+            self.worker_message_sender
+                .send(WorkerMessage::Log(line))
+                .ok();
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Installs a `tracing` subscriber that formats events and forwards each line as a
+// `WorkerMessage::Log`. Safe to call more than once per process; later calls are ignored.
+pub fn init(worker_message_sender: Sender<WorkerMessage>) {
+    let _ = tracing_subscriber::fmt()
+        .with_writer(move || ChannelWriter {
+            worker_message_sender: worker_message_sender.clone(),
+        })
+        .with_target(false)
+        .without_time()
+        .try_init();
+}
@@ -1,9 +1,20 @@
 mod actions;
+mod splash;
 mod ui;
 
-use eframe::egui::{IconData, ViewportBuilder};
+use eframe::egui::{self, IconData, ViewportBuilder};
 use ico::IconDir;
-use std::{collections::BTreeMap, env, fs, io::Cursor, sync::mpsc, thread};
+use splash::SplashView;
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::Cursor,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+};
 use ui::Launcher;
 
 pub struct ReleaseChannelsInfo {
@@ -14,11 +25,17 @@ pub struct ReleaseChannelsInfo {
 pub struct Progress {
     message: String,
     progress: f32,
+    downloaded_bytes: u64,
+    total_bytes: Option<u64>,
+    bytes_per_sec: f32,
 }
 
 pub enum WorkerMessage {
     ReleaseChannelsInfo(ReleaseChannelsInfo),
     ProgressUpdate(Progress),
+    // A formatted `tracing` event from the worker thread. `Launcher` stores these but does not
+    // display them.
+    Log(String),
     Done,
     Error(String),
 }
@@ -35,6 +52,11 @@ pub enum UiMessage {
         session_version: Option<String>,
     },
     InstallClient(ReleaseInfo),
+    // Bytes per second. `None` removes the cap.
+    SetDownloadSpeedLimit(Option<u64>),
+    PauseDownload,
+    ResumeDownload,
+    CancelDownload,
     Quit,
 }
 
@@ -44,19 +66,64 @@ pub struct InstallationInfo {
     has_session_json: bool, // Only relevant on Windows
 }
 
+// Shows the splash screen until the worker reports the first release channels (or an error),
+// then hands every frame to the real `Launcher`. Both live under the single `eframe::App`
+// instance created in `main`, so there's still only one winit window/event loop for the whole
+// process lifetime.
+struct App {
+    launcher: Launcher,
+    splash: SplashView,
+    splash_done: Arc<AtomicBool>,
+}
+
+impl eframe::App for App {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        if self.splash_done.load(Ordering::Acquire) {
+            self.launcher.update(ctx, frame);
+        } else {
+            self.splash.ui(ctx);
+        }
+    }
+}
+
 fn main() {
     let (worker_message_sender, worker_message_receiver) = mpsc::channel::<WorkerMessage>();
     let (ui_message_sender, ui_message_receiver) = mpsc::channel::<UiMessage>();
 
-    let worker_handle =
-        thread::spawn(|| actions::worker(ui_message_receiver, worker_message_sender));
-
     let ico = IconDir::read(Cursor::new(include_bytes!(
         "../../dashboard/resources/dashboard.ico"
     )))
     .unwrap();
     let image = ico.entries().first().unwrap().decode().unwrap();
 
+    let (raw_worker_message_sender, raw_worker_message_receiver) =
+        mpsc::channel::<WorkerMessage>();
+
+    let worker_handle =
+        thread::spawn(|| actions::worker(ui_message_receiver, raw_worker_message_sender));
+
+    // Forwards every worker message on to the UI thread's receiver, flipping `splash_done` the
+    // first time release channels (or an error) come back. This only moves data between
+    // channels, so it's safe to run on a background thread unlike a second `eframe::run_native`.
+    let splash_done = Arc::new(AtomicBool::new(false));
+    thread::spawn({
+        let splash_done = Arc::clone(&splash_done);
+        move || {
+            while let Ok(message) = raw_worker_message_receiver.recv() {
+                if matches!(
+                    message,
+                    WorkerMessage::ReleaseChannelsInfo(_) | WorkerMessage::Error(_)
+                ) {
+                    splash_done.store(true, Ordering::Release);
+                }
+
+                if worker_message_sender.send(message).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
     // Workaround for the steam deck
     if fs::read_to_string("/sys/devices/virtual/dmi/id/board_vendor")
         .map(|vendor| vendor.trim() == "Valve")
@@ -79,11 +146,14 @@ fn main() {
             ..Default::default()
         },
         Box::new(move |cc| {
-            Ok(Box::new(Launcher::new(
-                cc,
-                worker_message_receiver,
-                ui_message_sender,
-            )))
+            Ok(Box::new(App {
+                launcher: Launcher::new(cc, worker_message_receiver, ui_message_sender),
+                splash: SplashView::new(
+                    image.rgba_data().to_owned(),
+                    (image.width(), image.height()),
+                ),
+                splash_done,
+            }))
         }),
     )
     .expect("Failed to run eframe");
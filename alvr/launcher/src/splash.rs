@@ -0,0 +1,56 @@
+use eframe::egui::{self, CentralPanel, ColorImage, RichText, TextureHandle, TextureOptions};
+
+// Renders the "Fetching releases…" splash screen shown while the worker resolves release
+// channels over the network. This is plain `egui::Ui` drawing, not a standalone window: winit
+// only tolerates one `EventLoop` per process (and requires it be built on the main thread), so
+// the splash has to live inside the same `eframe::App`/`run_native` call as the real launcher
+// UI rather than owning a second one on a background thread.
+pub struct SplashView {
+    icon_rgba: Vec<u8>,
+    icon_size: (u32, u32),
+    // Loaded lazily on first paint and kept for the life of the splash screen. Re-requesting a
+    // texture from the same bytes every frame would re-upload it to the GPU on every repaint
+    // (the splash repaints ~10x/sec while waiting on the worker), dropping the previous handle
+    // each time.
+    icon_texture: Option<TextureHandle>,
+}
+
+impl SplashView {
+    pub fn new(icon_rgba: Vec<u8>, icon_size: (u32, u32)) -> Self {
+        Self {
+            icon_rgba,
+            icon_size,
+            icon_texture: None,
+        }
+    }
+
+    pub fn ui(&mut self, ctx: &egui::Context) {
+        let texture = self.icon_texture.get_or_insert_with(|| {
+            ctx.load_texture(
+                "splash-icon",
+                ColorImage::from_rgba_unmultiplied(
+                    [self.icon_size.0 as usize, self.icon_size.1 as usize],
+                    &self.icon_rgba,
+                ),
+                TextureOptions::default(),
+            )
+        });
+        let texture_id = texture.id();
+
+        CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(30.0);
+
+                ui.image((texture_id, egui::vec2(64.0, 64.0)));
+
+                ui.add_space(15.0);
+                ui.spinner();
+                ui.label(RichText::new("Fetching releases…").size(16.0));
+            });
+        });
+
+        // Nothing else will wake this viewport up while we're waiting on the worker thread;
+        // poll for it finishing a few times a second.
+        ctx.request_repaint_after(std::time::Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,244 @@
+// The launcher's main control surface: pick a release channel/version, install the server or
+// client, and watch (and control) the download in progress. Everything here talks to the
+// worker thread purely through `UiMessage`/`WorkerMessage` — no direct filesystem or network
+// access happens on this side.
+use crate::{Progress, ReleaseChannelsInfo, ReleaseInfo, UiMessage, WorkerMessage};
+use eframe::egui::{self, CentralPanel, ProgressBar, RichText, Slider};
+use std::sync::mpsc::{Receiver, Sender};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Channel {
+    Stable,
+    Nightly,
+}
+
+enum InstallStatus {
+    Idle,
+    InProgress(Progress),
+    Done,
+    Error(String),
+}
+
+pub struct Launcher {
+    worker_message_receiver: Receiver<WorkerMessage>,
+    ui_message_sender: Sender<UiMessage>,
+    release_channels: Option<ReleaseChannelsInfo>,
+    channel: Channel,
+    selected_version: Option<String>,
+    status: InstallStatus,
+    speed_limit_enabled: bool,
+    speed_limit_mbps: f32,
+    paused: bool,
+    log_lines: Vec<String>,
+}
+
+impl Launcher {
+    pub fn new(
+        _cc: &eframe::CreationContext<'_>,
+        worker_message_receiver: Receiver<WorkerMessage>,
+        ui_message_sender: Sender<UiMessage>,
+    ) -> Self {
+        Self {
+            worker_message_receiver,
+            ui_message_sender,
+            release_channels: None,
+            channel: Channel::Stable,
+            selected_version: None,
+            status: InstallStatus::Idle,
+            speed_limit_enabled: false,
+            speed_limit_mbps: 10.0,
+            paused: false,
+            log_lines: vec![],
+        }
+    }
+
+    fn channel_releases(&self) -> &[ReleaseInfo] {
+        let Some(info) = &self.release_channels else {
+            return &[];
+        };
+
+        match self.channel {
+            Channel::Stable => &info.stable,
+            Channel::Nightly => &info.nightly,
+        }
+    }
+
+    fn selected_release(&self) -> Option<&ReleaseInfo> {
+        let version = self.selected_version.as_ref()?;
+        self.channel_releases()
+            .iter()
+            .find(|release| &release.version == version)
+    }
+
+    fn drain_worker_messages(&mut self) {
+        while let Ok(message) = self.worker_message_receiver.try_recv() {
+            match message {
+                WorkerMessage::ReleaseChannelsInfo(info) => {
+                    self.selected_version = info.stable.first().map(|r| r.version.clone());
+                    self.release_channels = Some(info);
+                }
+                WorkerMessage::ProgressUpdate(progress) => {
+                    self.status = InstallStatus::InProgress(progress);
+                }
+                WorkerMessage::Log(line) => self.log_lines.push(line),
+                WorkerMessage::Done => self.status = InstallStatus::Done,
+                WorkerMessage::Error(e) => self.status = InstallStatus::Error(e),
+            }
+        }
+    }
+
+    fn send_speed_limit(&self) {
+        let limit = self
+            .speed_limit_enabled
+            .then_some((self.speed_limit_mbps * 1_000_000.0 / 8.0) as u64);
+
+        self.ui_message_sender
+            .send(UiMessage::SetDownloadSpeedLimit(limit))
+            .ok();
+    }
+
+    fn release_picker(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.channel, Channel::Stable, "Stable");
+            ui.selectable_value(&mut self.channel, Channel::Nightly, "Nightly");
+        });
+
+        egui::ComboBox::from_label("Version")
+            .selected_text(self.selected_version.clone().unwrap_or_default())
+            .show_ui(ui, |ui| {
+                for release in self.channel_releases() {
+                    ui.selectable_value(
+                        &mut self.selected_version,
+                        Some(release.version.clone()),
+                        &release.version,
+                    );
+                }
+            });
+    }
+
+    fn install_buttons(&mut self, ui: &mut egui::Ui) {
+        let Some(release_info) = self.selected_release().cloned() else {
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            if ui.button("Install server").clicked() {
+                self.send_speed_limit();
+                self.ui_message_sender
+                    .send(UiMessage::InstallServer {
+                        release_info: release_info.clone(),
+                        session_version: None,
+                    })
+                    .ok();
+            }
+
+            if ui.button("Install client APK").clicked() {
+                self.send_speed_limit();
+                self.ui_message_sender
+                    .send(UiMessage::InstallClient(release_info))
+                    .ok();
+            }
+        });
+    }
+
+    fn speed_limit_control(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .checkbox(&mut self.speed_limit_enabled, "Limit download speed")
+                .changed()
+            {
+                self.send_speed_limit();
+            }
+
+            ui.add_enabled_ui(self.speed_limit_enabled, |ui| {
+                if ui
+                    .add(Slider::new(&mut self.speed_limit_mbps, 0.5..=100.0).suffix(" Mbps"))
+                    .changed()
+                {
+                    self.send_speed_limit();
+                }
+            });
+        });
+    }
+
+    fn progress_ui(&mut self, ui: &mut egui::Ui) {
+        match &self.status {
+            InstallStatus::Idle => (),
+            InstallStatus::InProgress(progress) => {
+                ui.add_space(10.0);
+                ui.label(&progress.message);
+
+                ui.add(ProgressBar::new(progress.progress).show_percentage());
+
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "{:.1} / {}",
+                        progress.downloaded_bytes as f32 / 1_000_000.0,
+                        progress
+                            .total_bytes
+                            .map(|total| format!("{:.1} MB", total as f32 / 1_000_000.0))
+                            .unwrap_or_else(|| "? MB".to_owned())
+                    ));
+                    ui.label(format!(
+                        "{:.2} MB/s",
+                        progress.bytes_per_sec / 1_000_000.0
+                    ));
+
+                    if let Some(total_bytes) = progress.total_bytes {
+                        if progress.bytes_per_sec > 0.0 {
+                            let remaining_secs = (total_bytes - progress.downloaded_bytes) as f32
+                                / progress.bytes_per_sec;
+                            ui.label(format!("ETA {remaining_secs:.0}s"));
+                        }
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    let pause_label = if self.paused { "Resume" } else { "Pause" };
+                    if ui.button(pause_label).clicked() {
+                        self.paused = !self.paused;
+                        let message = if self.paused {
+                            UiMessage::PauseDownload
+                        } else {
+                            UiMessage::ResumeDownload
+                        };
+                        self.ui_message_sender.send(message).ok();
+                    }
+
+                    if ui.button("Cancel").clicked() {
+                        self.paused = false;
+                        self.ui_message_sender.send(UiMessage::CancelDownload).ok();
+                        self.status = InstallStatus::Idle;
+                    }
+                });
+            }
+            InstallStatus::Done => {
+                ui.add_space(10.0);
+                ui.label(RichText::new("Done").strong());
+            }
+            InstallStatus::Error(e) => {
+                ui.add_space(10.0);
+                ui.colored_label(egui::Color32::RED, e);
+            }
+        }
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.drain_worker_messages();
+
+        CentralPanel::default().show(ctx, |ui| {
+            ui.heading("ALVR Launcher");
+            ui.add_space(10.0);
+
+            self.release_picker(ui);
+            ui.add_space(10.0);
+            self.speed_limit_control(ui);
+            ui.add_space(10.0);
+            self.install_buttons(ui);
+
+            self.progress_ui(ui);
+        });
+
+        ctx.request_repaint_after(std::time::Duration::from_millis(200));
+    }
+}